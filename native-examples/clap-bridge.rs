@@ -14,6 +14,7 @@
  * - Rust 1.70+
  * - clap-sys crate
  * - libloading crate
+ * - crossbeam crate (lock-free audio-thread state)
  * - napi-rs for Node.js bindings OR tauri for Tauri
  *
  * BUILD:
@@ -25,12 +26,21 @@
  */
 
 use napi_derive::napi;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_void};
 use std::path::Path;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::{Arc, Mutex};
 use libloading::{Library, Symbol};
+use napi::bindgen_prelude::{Buffer, Float32Array};
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::JsFunction;
+use crossbeam::atomic::AtomicCell;
+use crossbeam::queue::ArrayQueue;
 
 // CLAP API types (simplified - use clap-sys crate in production)
 type ClapVersion = [u32; 3];
@@ -49,6 +59,138 @@ struct ClapPluginDescriptor {
     features: *const *const c_char,
 }
 
+/**
+ * The symbol every CLAP shared library exports as `clap_entry`. This is the
+ * single entry point a host uses to reach everything else in the bundle.
+ */
+#[repr(C)]
+struct ClapPluginEntry {
+    clap_version: ClapVersion,
+    init: unsafe extern "C" fn(plugin_path: *const c_char) -> bool,
+    deinit: unsafe extern "C" fn(),
+    get_factory: unsafe extern "C" fn(factory_id: *const c_char) -> *const c_void,
+}
+
+/**
+ * `clap.plugin-factory`: enumerates and instantiates the plugins a bundle
+ * contains. A single `.clap` file can expose more than one plugin id.
+ */
+#[repr(C)]
+struct ClapPluginFactory {
+    get_plugin_count: unsafe extern "C" fn(factory: *const ClapPluginFactory) -> u32,
+    get_plugin_descriptor: unsafe extern "C" fn(
+        factory: *const ClapPluginFactory,
+        index: u32,
+    ) -> *const ClapPluginDescriptor,
+    create_plugin: unsafe extern "C" fn(
+        factory: *const ClapPluginFactory,
+        host: *const ClapHost,
+        plugin_id: *const c_char,
+    ) -> *const ClapPlugin,
+}
+
+/**
+ * The host-side vtable a plugin is handed at `create_plugin` time and calls
+ * back into for the lifetime of the instance. `host_data` carries our
+ * `HostState` so the callbacks (free `extern "C" fn`s, not closures) can
+ * reach back into bridge state.
+ */
+#[repr(C)]
+struct ClapHost {
+    clap_version: ClapVersion,
+    host_data: *mut c_void,
+    name: *const c_char,
+    vendor: *const c_char,
+    url: *const c_char,
+    version: *const c_char,
+    get_extension:
+        unsafe extern "C" fn(host: *const ClapHost, extension_id: *const c_char) -> *const c_void,
+    request_restart: unsafe extern "C" fn(host: *const ClapHost),
+    request_process: unsafe extern "C" fn(host: *const ClapHost),
+    request_callback: unsafe extern "C" fn(host: *const ClapHost),
+}
+
+/**
+ * Mutable state a `ClapHost` points to via `host_data`. Holds the strings the
+ * host struct's `*const c_char` fields borrow (so they outlive the struct)
+ * and the flags the plugin trips through its callbacks.
+ */
+struct HostState {
+    name: CString,
+    vendor: CString,
+    url: CString,
+    version: CString,
+    on_main_thread_pending: AtomicBool,
+    restart_requested: AtomicBool,
+}
+
+unsafe extern "C" fn host_get_extension(
+    _host: *const ClapHost,
+    _extension_id: *const c_char,
+) -> *const c_void {
+    // No optional host extensions (timer-support, log, thread-check, ...) yet.
+    std::ptr::null()
+}
+
+unsafe extern "C" fn host_request_restart(host: *const ClapHost) {
+    if let Some(state) = host_state_of(host) {
+        state.restart_requested.store(true, Ordering::SeqCst);
+    }
+}
+
+unsafe extern "C" fn host_request_process(_host: *const ClapHost) {
+    // The bridge always re-invokes process() on the next audio block, so
+    // there is no separate scheduling queue to mark here.
+}
+
+unsafe extern "C" fn host_request_callback(host: *const ClapHost) {
+    if let Some(state) = host_state_of(host) {
+        state.on_main_thread_pending.store(true, Ordering::SeqCst);
+    }
+}
+
+unsafe fn host_state_of<'a>(host: *const ClapHost) -> Option<&'a HostState> {
+    if host.is_null() {
+        return None;
+    }
+    let data = (*host).host_data;
+    if data.is_null() {
+        return None;
+    }
+    Some(&*(data as *const HostState))
+}
+
+/**
+ * Build a boxed, pinned `ClapHost` (and the `HostState` it points into) for a
+ * single plugin instance. The pair is kept alive inside the plugin's
+ * `ClapPluginHandle` for as long as the instance exists.
+ */
+fn build_host() -> (Pin<Box<HostState>>, Pin<Box<ClapHost>>) {
+    let state = Box::pin(HostState {
+        name: CString::new("Dawg AI Web").unwrap(),
+        vendor: CString::new("Dawg AI").unwrap(),
+        url: CString::new("https://dawg.ai").unwrap(),
+        version: CString::new(env!("CARGO_PKG_VERSION")).unwrap_or_else(|_| CString::new("0.0.0").unwrap()),
+        on_main_thread_pending: AtomicBool::new(false),
+        restart_requested: AtomicBool::new(false),
+    });
+
+    let host = Box::pin(ClapHost {
+        clap_version: [1, 2, 0],
+        host_data: &*state as *const HostState as *mut c_void,
+        name: state.name.as_ptr(),
+        vendor: state.vendor.as_ptr(),
+        url: state.url.as_ptr(),
+        version: state.version.as_ptr(),
+        get_extension: host_get_extension,
+        request_restart: host_request_restart,
+        request_process: host_request_process,
+        request_callback: host_request_callback,
+    });
+
+    (state, host)
+}
+
 #[repr(C)]
 struct ClapPlugin {
     desc: *const ClapPluginDescriptor,
@@ -89,6 +231,509 @@ struct ClapProcess {
     out_events: *const c_void,
 }
 
+/**
+ * A single `clap_audio_buffer`: one bus, made of one pointer-per-channel.
+ * We only ever populate `data32` (the bridge works in f32 throughout).
+ */
+#[repr(C)]
+struct ClapAudioBuffer {
+    data32: *mut *mut f32,
+    data64: *mut *mut f64,
+    channel_count: u32,
+    latency: u32,
+    constant_mask: u64,
+}
+
+/**
+ * `clap.audio-ports`: lets a plugin declare its real bus/channel layout
+ * instead of the host guessing stereo-in/stereo-out.
+ */
+#[repr(C)]
+struct ClapPluginAudioPorts {
+    count: unsafe extern "C" fn(plugin: *const ClapPlugin, is_input: bool) -> u32,
+    get: unsafe extern "C" fn(
+        plugin: *const ClapPlugin,
+        index: u32,
+        is_input: bool,
+        info: *mut ClapAudioPortInfoRaw,
+    ) -> bool,
+}
+
+/**
+ * Raw `clap_audio_port_info` the audio-ports extension fills in for us.
+ * `name` is a fixed buffer (as in the real ABI) rather than a pointer since
+ * the plugin writes directly into it.
+ */
+#[repr(C)]
+struct ClapAudioPortInfoRaw {
+    id: u32,
+    name: [c_char; 256],
+    flags: u32,
+    channel_count: u32,
+    port_type: *const c_char,
+    in_place_pair: u32,
+}
+
+/**
+ * A bundle's discovered channel layout, read lock-free from the audio
+ * thread via an `AtomicCell` so `process` can size buffers without ever
+ * touching the registry mutex.
+ */
+#[derive(Clone, Copy, Default)]
+struct BusConfig {
+    num_input_channels: u32,
+    num_output_channels: u32,
+}
+
+/**
+ * The sample rate and block size a plugin was last activated with.
+ */
+#[derive(Clone, Copy, Default)]
+struct BufferConfig {
+    sample_rate: f64,
+    max_block_size: u32,
+}
+
+/**
+ * Audio port info surfaced to JS, as returned by `get_audio_ports`.
+ */
+#[napi(object)]
+pub struct AudioPortInfo {
+    pub id: u32,
+    pub name: String,
+    pub channel_count: u32,
+    pub flags: u32,
+    pub port_type: String,
+}
+
+/**
+ * Read a fixed-size, NUL-terminated `c_char` buffer (as used by
+ * `clap_audio_port_info::name`) into an owned `String`.
+ */
+unsafe fn fixed_cstr_to_string(buf: &[c_char]) -> String {
+    let bytes = std::slice::from_raw_parts(buf.as_ptr() as *const u8, buf.len());
+    let nul_pos = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..nul_pos]).into_owned()
+}
+
+const CLAP_EVENT_PARAM_VALUE: u16 = 5;
+const CLAP_EVENT_NOTE_END: u16 = 3;
+const CLAP_EVENT_MIDI: u16 = 10;
+
+/// `clap_event_note`: used here only for `CLAP_EVENT_NOTE_END` out-events.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ClapEventNote {
+    header: ClapEventHeader,
+    note_id: i32,
+    port_index: i16,
+    channel: i16,
+    key: i16,
+    velocity: f64,
+}
+
+/// `clap_event_midi`: a single 3-byte MIDI 1.0 message on a given port.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ClapEventMidi {
+    header: ClapEventHeader,
+    port_index: u16,
+    data: [u8; 3],
+}
+
+/**
+ * An out-event collected off the realtime audio thread, in a form cheap
+ * enough to build inside `try_push` with no allocation beyond the
+ * preallocated `Vec` it lands in.
+ */
+#[derive(Clone, Copy)]
+enum CollectedEvent {
+    ParamValue { param_id: u32, value: f64 },
+    NoteEnd { note_id: i32, key: i16, channel: i16 },
+    Midi { port_index: u16, data: [u8; 3] },
+}
+
+/**
+ * Parameter/MIDI-out event surfaced to JS, as delivered through the
+ * `on_plugin_events` threadsafe function.
+ */
+#[napi(object)]
+pub struct PluginEvent {
+    pub event_type: String,
+    pub param_id: Option<u32>,
+    pub value: Option<f64>,
+    pub midi_data: Option<Vec<u8>>,
+    pub note_id: Option<i32>,
+    pub key: Option<i16>,
+    pub channel: Option<i16>,
+}
+
+impl From<CollectedEvent> for PluginEvent {
+    fn from(event: CollectedEvent) -> Self {
+        match event {
+            CollectedEvent::ParamValue { param_id, value } => PluginEvent {
+                event_type: "param_value".to_string(),
+                param_id: Some(param_id),
+                value: Some(value),
+                midi_data: None,
+                note_id: None,
+                key: None,
+                channel: None,
+            },
+            CollectedEvent::NoteEnd {
+                note_id,
+                key,
+                channel,
+            } => PluginEvent {
+                event_type: "note_end".to_string(),
+                param_id: None,
+                value: None,
+                midi_data: None,
+                note_id: Some(note_id),
+                key: Some(key),
+                channel: Some(channel),
+            },
+            CollectedEvent::Midi { port_index, data } => PluginEvent {
+                event_type: "midi".to_string(),
+                param_id: Some(port_index as u32),
+                value: None,
+                midi_data: Some(data.to_vec()),
+                note_id: None,
+                key: None,
+                channel: None,
+            },
+        }
+    }
+}
+
+/// Cap on how many out-events a single `process()` block collects; this is
+/// a UI-automation feed, not a sample-accurate event log.
+const OUT_EVENTS_CAPACITY: usize = 256;
+
+/**
+ * Backing store for a `ClapOutputEvents` built for one `process()` call.
+ * `try_push` copies just enough of each event to describe it to JS later,
+ * entirely off the heap-allocating path (the `Vec` is preallocated).
+ */
+struct OutputEventsCtx {
+    events: Vec<CollectedEvent>,
+}
+
+unsafe extern "C" fn collect_output_event(
+    list: *const ClapOutputEvents,
+    event: *const ClapEventHeader,
+) -> bool {
+    let ctx = (*list).ctx as *mut OutputEventsCtx;
+    if ctx.is_null() || event.is_null() {
+        return false;
+    }
+    let ctx = &mut *ctx;
+    if ctx.events.len() >= ctx.events.capacity() {
+        return false;
+    }
+
+    let collected = match (*event).event_type {
+        CLAP_EVENT_PARAM_VALUE => {
+            let ev = event as *const ClapEventParamValue;
+            CollectedEvent::ParamValue {
+                param_id: (*ev).param_id,
+                value: (*ev).value,
+            }
+        }
+        CLAP_EVENT_NOTE_END => {
+            let ev = event as *const ClapEventNote;
+            CollectedEvent::NoteEnd {
+                note_id: (*ev).note_id,
+                key: (*ev).key,
+                channel: (*ev).channel,
+            }
+        }
+        CLAP_EVENT_MIDI => {
+            let ev = event as *const ClapEventMidi;
+            CollectedEvent::Midi {
+                port_index: (*ev).port_index,
+                data: (*ev).data,
+            }
+        }
+        _ => return false,
+    };
+
+    ctx.events.push(collected);
+    true
+}
+
+fn build_collecting_output_events(ctx: &mut OutputEventsCtx) -> ClapOutputEvents {
+    ClapOutputEvents {
+        ctx: ctx as *mut OutputEventsCtx as *mut c_void,
+        try_push: collect_output_event,
+    }
+}
+
+/**
+ * `clap_event_header`: the common prefix every event in an event list
+ * starts with, so a host can walk a list of mixed event types by `size`
+ * alone.
+ */
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ClapEventHeader {
+    size: u32,
+    time: u32,
+    space_id: u16,
+    event_type: u16,
+    flags: u32,
+}
+
+/**
+ * `clap_event_param_value`: a parameter change, delivered to the plugin via
+ * an input event list rather than a direct setter call.
+ */
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ClapEventParamValue {
+    header: ClapEventHeader,
+    param_id: u32,
+    cookie: *mut c_void,
+    note_id: i32,
+    port_index: i16,
+    channel: i16,
+    key: i16,
+    value: f64,
+}
+
+unsafe impl Send for ClapEventParamValue {}
+
+/**
+ * `clap_input_events`: the list a host hands a plugin for `process()`/
+ * `flush()`. `ctx` points at whatever backs `size`/`get` for this call.
+ */
+#[repr(C)]
+struct ClapInputEvents {
+    ctx: *const c_void,
+    size: unsafe extern "C" fn(list: *const ClapInputEvents) -> u32,
+    get: unsafe extern "C" fn(list: *const ClapInputEvents, index: u32) -> *const ClapEventHeader,
+}
+
+/**
+ * `clap_output_events`: the list a plugin pushes automation/MIDI-out events
+ * into during `process()`/`flush()`.
+ */
+#[repr(C)]
+struct ClapOutputEvents {
+    ctx: *mut c_void,
+    try_push: unsafe extern "C" fn(list: *const ClapOutputEvents, event: *const ClapEventHeader) -> bool,
+}
+
+/**
+ * Backing store for a `ClapInputEvents` built from a drained batch of
+ * pending parameter events.
+ */
+struct PendingParamEvents {
+    events: Vec<ClapEventParamValue>,
+}
+
+unsafe extern "C" fn param_events_size(list: *const ClapInputEvents) -> u32 {
+    let ctx = (*list).ctx as *const PendingParamEvents;
+    (*ctx).events.len() as u32
+}
+
+unsafe extern "C" fn param_events_get(
+    list: *const ClapInputEvents,
+    index: u32,
+) -> *const ClapEventHeader {
+    let ctx = (*list).ctx as *const PendingParamEvents;
+    match (*ctx).events.get(index as usize) {
+        Some(event) => &event.header as *const ClapEventHeader,
+        None => std::ptr::null(),
+    }
+}
+
+/**
+ * An output event list that rejects every event. Used for `params->flush()`
+ * in `set_parameter_value`, whose out-events are not forwarded to JS.
+ */
+unsafe extern "C" fn reject_output_event(
+    _list: *const ClapOutputEvents,
+    _event: *const ClapEventHeader,
+) -> bool {
+    false
+}
+
+fn build_input_events(events: &PendingParamEvents) -> ClapInputEvents {
+    ClapInputEvents {
+        ctx: events as *const PendingParamEvents as *const c_void,
+        size: param_events_size,
+        get: param_events_get,
+    }
+}
+
+fn build_empty_output_events() -> ClapOutputEvents {
+    ClapOutputEvents {
+        ctx: std::ptr::null_mut(),
+        try_push: reject_output_event,
+    }
+}
+
+/**
+ * `clap.params`: parameter discovery plus the `flush()` entry point used to
+ * deliver value changes outside of `process()`.
+ */
+#[repr(C)]
+struct ClapPluginParams {
+    count: unsafe extern "C" fn(plugin: *const ClapPlugin) -> u32,
+    get_info:
+        unsafe extern "C" fn(plugin: *const ClapPlugin, index: u32, info: *mut ClapParamInfoRaw) -> bool,
+    get_value: unsafe extern "C" fn(plugin: *const ClapPlugin, param_id: u32, value: *mut f64) -> bool,
+    value_to_text: unsafe extern "C" fn(
+        plugin: *const ClapPlugin,
+        param_id: u32,
+        value: f64,
+        out: *mut c_char,
+        out_size: u32,
+    ) -> bool,
+    text_to_value: unsafe extern "C" fn(
+        plugin: *const ClapPlugin,
+        param_id: u32,
+        text: *const c_char,
+        out: *mut f64,
+    ) -> bool,
+    flush: unsafe extern "C" fn(
+        plugin: *const ClapPlugin,
+        in_events: *const ClapInputEvents,
+        out_events: *const ClapOutputEvents,
+    ),
+}
+
+/**
+ * Raw `clap_param_info`, filled in by the params extension's `get_info`.
+ */
+#[repr(C)]
+struct ClapParamInfoRaw {
+    id: u32,
+    flags: u32,
+    cookie: *mut c_void,
+    name: [c_char; 256],
+    module: [c_char; 1024],
+    min_value: f64,
+    max_value: f64,
+    default_value: f64,
+}
+
+/**
+ * Parameter info surfaced to JS, as returned by `get_parameter_info`.
+ */
+#[napi(object)]
+pub struct ParamInfo {
+    pub id: u32,
+    pub name: String,
+    pub min_value: f64,
+    pub max_value: f64,
+    pub default_value: f64,
+    pub flags: u32,
+}
+
+/**
+ * Look up a plugin's `clap.params` extension, or null if it doesn't
+ * implement one.
+ */
+unsafe fn params_extension_of(plugin: *const ClapPlugin) -> *const ClapPluginParams {
+    let extension_id = CString::new("clap.params").unwrap();
+    ((*plugin).get_extension)(plugin, extension_id.as_ptr()) as *const ClapPluginParams
+}
+
+/**
+ * `clap_ostream`: a host-provided sink a plugin's `state->save()` writes
+ * serialized preset bytes into.
+ */
+#[repr(C)]
+struct ClapOStream {
+    ctx: *mut c_void,
+    write: unsafe extern "C" fn(stream: *const ClapOStream, buffer: *const c_void, size: u64) -> i64,
+}
+
+/**
+ * `clap_istream`: a host-provided source a plugin's `state->load()` reads
+ * serialized preset bytes back from.
+ */
+#[repr(C)]
+struct ClapIStream {
+    ctx: *mut c_void,
+    read: unsafe extern "C" fn(stream: *const ClapIStream, buffer: *mut c_void, size: u64) -> i64,
+}
+
+/**
+ * `clap.state`: full-preset save/restore through a pair of stream
+ * callbacks, rather than returning a single buffer in one call.
+ */
+#[repr(C)]
+struct ClapPluginState {
+    save: unsafe extern "C" fn(plugin: *const ClapPlugin, stream: *const ClapOStream) -> bool,
+    load: unsafe extern "C" fn(plugin: *const ClapPlugin, stream: *const ClapIStream) -> bool,
+}
+
+/// Backing store for a `ClapOStream` during `save_state`.
+struct OStreamCtx {
+    buffer: Vec<u8>,
+}
+
+/// Backing store for a `ClapIStream` during `load_state`.
+struct IStreamCtx {
+    data: Vec<u8>,
+    offset: usize,
+}
+
+unsafe extern "C" fn ostream_write(
+    stream: *const ClapOStream,
+    data: *const c_void,
+    size: u64,
+) -> i64 {
+    let ctx = (*stream).ctx as *mut OStreamCtx;
+    if ctx.is_null() || (data.is_null() && size > 0) {
+        return -1;
+    }
+    let slice = std::slice::from_raw_parts(data as *const u8, size as usize);
+    (*ctx).buffer.extend_from_slice(slice);
+    size as i64
+}
+
+unsafe extern "C" fn istream_read(stream: *const ClapIStream, buffer: *mut c_void, size: u64) -> i64 {
+    let ctx = (*stream).ctx as *mut IStreamCtx;
+    if ctx.is_null() || (buffer.is_null() && size > 0) {
+        return -1;
+    }
+    let ctx = &mut *ctx;
+    if ctx.offset >= ctx.data.len() {
+        return 0; // EOF
+    }
+    let remaining = ctx.data.len() - ctx.offset;
+    let to_copy = remaining.min(size as usize);
+    std::ptr::copy_nonoverlapping(ctx.data[ctx.offset..].as_ptr(), buffer as *mut u8, to_copy);
+    ctx.offset += to_copy;
+    to_copy as i64
+}
+
+/**
+ * Look up a plugin's `clap.state` extension, or null if it doesn't
+ * implement one.
+ */
+unsafe fn state_extension_of(plugin: *const ClapPlugin) -> *const ClapPluginState {
+    let extension_id = CString::new("clap.state").unwrap();
+    ((*plugin).get_extension)(plugin, extension_id.as_ptr()) as *const ClapPluginState
+}
+
+/**
+ * Descriptor info surfaced to JS for a single plugin inside a bundle, as
+ * returned by `list_plugins`, `scan_plugins` and `rescan`.
+ */
+#[napi(object)]
+#[derive(Clone)]
+pub struct PluginInfo {
+    pub id: String,
+    pub name: String,
+    pub vendor: String,
+    pub features: Vec<String>,
+}
+
 /**
  * CLAP Plugin Handle
  */
@@ -96,12 +741,27 @@ struct ClapPluginHandle {
     id: u32,
     path: String,
     library: Library,
+    entry: *const ClapPluginEntry,
+    factory: *const ClapPluginFactory,
     plugin: *const ClapPlugin,
+    host_state: Pin<Box<HostState>>,
+    host: Pin<Box<ClapHost>>,
     initialized: bool,
     activated: bool,
     processing: bool,
+    activation_params: Option<(f64, u32, u32)>,
+    steady_time: Arc<AtomicI64>,
+    bus_config: Arc<AtomicCell<BusConfig>>,
+    input_port_channels: Arc<Mutex<Vec<u32>>>,
+    output_port_channels: Arc<Mutex<Vec<u32>>>,
+    buffer_config: BufferConfig,
+    param_events: Arc<ArrayQueue<ClapEventParamValue>>,
+    events_callback: Option<ThreadsafeFunction<Vec<PluginEvent>, ErrorStrategy::Fatal>>,
 }
 
+/// Pending parameter events a single block might realistically see.
+const PARAM_EVENT_QUEUE_CAPACITY: usize = 4096;
+
 /**
  * Global plugin storage
  */
@@ -125,11 +785,111 @@ fn init_registry() {
     }
 }
 
+/**
+ * Read a NUL-terminated C string into an owned `String`, treating a null
+ * pointer as empty rather than panicking (CLAP descriptors leave optional
+ * fields null).
+ */
+unsafe fn cstr_to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(ptr).to_string_lossy().into_owned()
+}
+
+/**
+ * Walk a descriptor's null-terminated `features` array into a `Vec<String>`.
+ */
+unsafe fn descriptor_features(desc: *const ClapPluginDescriptor) -> Vec<String> {
+    let mut features = Vec::new();
+    let mut cursor = (*desc).features;
+    if cursor.is_null() {
+        return features;
+    }
+    while !(*cursor).is_null() {
+        features.push(cstr_to_string(*cursor));
+        cursor = cursor.add(1);
+    }
+    features
+}
+
+unsafe fn descriptor_to_info(desc: *const ClapPluginDescriptor) -> PluginInfo {
+    PluginInfo {
+        id: cstr_to_string((*desc).id),
+        name: cstr_to_string((*desc).name),
+        vendor: cstr_to_string((*desc).vendor),
+        features: descriptor_features(desc),
+    }
+}
+
+/**
+ * Load the `clap_entry` symbol from a bundle and run the `init`/`get_factory`
+ * handshake, returning the entry and factory pointers for the caller to use.
+ */
+unsafe fn open_bundle(
+    library: &Library,
+    path: &str,
+) -> napi::Result<(*const ClapPluginEntry, *const ClapPluginFactory)> {
+    let clap_entry: Symbol<*const ClapPluginEntry> = library
+        .get(b"clap_entry")
+        .map_err(|e| napi::Error::from_reason(format!("Failed to find clap_entry: {}", e)))?;
+
+    let entry: *const ClapPluginEntry = *clap_entry;
+    if entry.is_null() {
+        return Err(napi::Error::from_reason("clap_entry symbol is null"));
+    }
+
+    let path_cstring = CString::new(path)
+        .map_err(|e| napi::Error::from_reason(format!("Invalid plugin path: {}", e)))?;
+    if !((*entry).init)(path_cstring.as_ptr()) {
+        return Err(napi::Error::from_reason("entry->init() failed"));
+    }
+
+    let factory_id = CString::new("clap.plugin-factory").unwrap();
+    let factory = ((*entry).get_factory)(factory_id.as_ptr()) as *const ClapPluginFactory;
+    if factory.is_null() {
+        ((*entry).deinit)();
+        return Err(napi::Error::from_reason(
+            "Bundle does not expose clap.plugin-factory",
+        ));
+    }
+
+    Ok((entry, factory))
+}
+
+/**
+ * List every plugin a `.clap` bundle exposes, without instantiating any of
+ * them. Opens the bundle just long enough to walk the factory's descriptors.
+ */
+#[napi]
+pub fn list_plugins(path: String) -> napi::Result<Vec<PluginInfo>> {
+    let library = unsafe {
+        Library::new(&path)
+            .map_err(|e| napi::Error::from_reason(format!("Failed to load library: {}", e)))?
+    };
+
+    let (entry, factory) = unsafe { open_bundle(&library, &path)? };
+
+    let count = unsafe { ((*factory).get_plugin_count)(factory) };
+    let mut infos = Vec::with_capacity(count as usize);
+    for index in 0..count {
+        let desc = unsafe { ((*factory).get_plugin_descriptor)(factory, index) };
+        if desc.is_null() {
+            continue;
+        }
+        infos.push(unsafe { descriptor_to_info(desc) });
+    }
+
+    unsafe { ((*entry).deinit)() };
+
+    Ok(infos)
+}
+
 /**
  * Load a CLAP plugin
  */
 #[napi]
-pub async fn load_plugin(path: String) -> napi::Result<u32> {
+pub async fn load_plugin(path: String, plugin_id: Option<String>) -> napi::Result<u32> {
     init_registry();
 
     // Load dynamic library
@@ -138,26 +898,43 @@ pub async fn load_plugin(path: String) -> napi::Result<u32> {
             .map_err(|e| napi::Error::from_reason(format!("Failed to load library: {}", e)))?
     };
 
-    // Get clap_entry symbol
-    let clap_entry: Symbol<unsafe extern "C" fn() -> *const c_void> = unsafe {
-        library
-            .get(b"clap_entry")
-            .map_err(|e| napi::Error::from_reason(format!("Failed to find clap_entry: {}", e)))?
-    };
+    let (entry, factory) = unsafe { open_bundle(&library, &path)? };
 
-    let entry_ptr = unsafe { clap_entry() };
-    if entry_ptr.is_null() {
-        return Err(napi::Error::from_reason("clap_entry returned null"));
+    let count = unsafe { ((*factory).get_plugin_count)(factory) };
+    let mut chosen: Option<*const ClapPluginDescriptor> = None;
+    for index in 0..count {
+        let desc = unsafe { ((*factory).get_plugin_descriptor)(factory, index) };
+        if desc.is_null() {
+            continue;
+        }
+        match &plugin_id {
+            Some(wanted) if unsafe { cstr_to_string((*desc).id) } == *wanted => {
+                chosen = Some(desc);
+                break;
+            }
+            None if chosen.is_none() => chosen = Some(desc),
+            _ => {}
+        }
     }
 
-    // In real implementation:
-    // 1. Call entry->init()
-    // 2. Get factory from entry->get_factory()
-    // 3. Get plugin descriptor from factory
-    // 4. Create plugin instance via factory->create_plugin()
+    let desc = match chosen {
+        Some(desc) => desc,
+        None => {
+            unsafe { ((*entry).deinit)() };
+            return Err(napi::Error::from_reason(format!(
+                "Bundle at {} does not contain the requested plugin",
+                path
+            )));
+        }
+    };
 
-    // For this example, assume we have a plugin pointer
-    let plugin: *const ClapPlugin = entry_ptr as *const ClapPlugin;
+    let (host_state, host) = build_host();
+    let plugin =
+        unsafe { ((*factory).create_plugin)(factory, &*host as *const ClapHost, (*desc).id) };
+    if plugin.is_null() {
+        unsafe { ((*entry).deinit)() };
+        return Err(napi::Error::from_reason("factory->create_plugin() failed"));
+    }
 
     // Create handle
     let mut registry = REGISTRY.lock().unwrap();
@@ -170,10 +947,22 @@ pub async fn load_plugin(path: String) -> napi::Result<u32> {
         id,
         path: path.clone(),
         library,
+        entry,
+        factory,
         plugin,
+        host_state,
+        host,
         initialized: false,
         activated: false,
         processing: false,
+        activation_params: None,
+        steady_time: Arc::new(AtomicI64::new(0)),
+        bus_config: Arc::new(AtomicCell::new(BusConfig::default())),
+        input_port_channels: Arc::new(Mutex::new(Vec::new())),
+        output_port_channels: Arc::new(Mutex::new(Vec::new())),
+        buffer_config: BufferConfig::default(),
+        param_events: Arc::new(ArrayQueue::new(PARAM_EVENT_QUEUE_CAPACITY)),
+        events_callback: None,
     });
 
     registry.plugins.insert(id, handle);
@@ -196,13 +985,25 @@ pub async fn unload_plugin(handle: u32) -> napi::Result<()> {
         .remove(&handle)
         .ok_or_else(|| napi::Error::from_reason("Invalid plugin handle"))?;
 
-    // Call plugin destroy
-    if !plugin_handle.plugin.is_null() {
+    // Call plugin destroy
+    if !plugin_handle.plugin.is_null() {
+        unsafe {
+            ((*plugin_handle.plugin).destroy)(plugin_handle.plugin);
+        }
+    }
+
+    if !plugin_handle.entry.is_null() {
         unsafe {
-            ((*plugin_handle.plugin).destroy)(plugin_handle.plugin);
+            ((*plugin_handle.entry).deinit)();
         }
     }
 
+    if let Some(tsfn) = plugin_handle.events_callback {
+        // Release the threadsafe function so the handle (and the JS
+        // callback it held a reference to) can drop cleanly.
+        let _ = tsfn.abort();
+    }
+
     println!("[ClapBridge] Unloaded plugin (id: {})", handle);
 
     Ok(())
@@ -268,12 +1069,218 @@ pub async fn activate(
 
     if success {
         plugin_handle.activated = true;
+        plugin_handle.activation_params = Some((sample_rate, min_frames, max_frames));
+        plugin_handle.buffer_config = BufferConfig {
+            sample_rate,
+            max_block_size: max_frames,
+        };
         println!("[ClapBridge] Activated plugin (id: {}) at {}Hz", handle, sample_rate);
     }
 
     Ok(success)
 }
 
+/**
+ * Check that a direction's discovered per-port layout accounts for every
+ * channel buffer JS handed us. Only the summed channel total is compared:
+ * the flat channel list carries no bus boundaries of its own.
+ */
+fn validate_bus_layout(direction: &str, layout: &[u32], provided: usize) -> napi::Result<()> {
+    let expected: u32 = layout.iter().sum();
+    if expected as usize != provided {
+        return Err(napi::Error::from_reason(format!(
+            "{} layout expects {} channels across {} bus(es) ({:?}), got {}",
+            direction,
+            expected,
+            layout.len(),
+            layout,
+            provided
+        )));
+    }
+    Ok(())
+}
+
+/**
+ * Slice a flat, concatenated channel-pointer list into one `ClapAudioBuffer`
+ * per bus, using each bus's channel count from the discovered port layout.
+ * Ports are assumed to appear back-to-back in port order, which is also
+ * the order JS is expected to hand us channels in.
+ */
+fn build_bus_buffers(
+    channel_ptrs: &mut [*mut f32],
+    port_channel_counts: &[u32],
+) -> napi::Result<Vec<ClapAudioBuffer>> {
+    let mut buffers = Vec::with_capacity(port_channel_counts.len());
+    let mut offset = 0usize;
+    for &count in port_channel_counts {
+        let count = count as usize;
+        if offset + count > channel_ptrs.len() {
+            return Err(napi::Error::from_reason(format!(
+                "bus layout expects {} channels but only {} were provided",
+                port_channel_counts.iter().sum::<u32>(),
+                channel_ptrs.len()
+            )));
+        }
+        let bus_channels = &mut channel_ptrs[offset..offset + count];
+        buffers.push(ClapAudioBuffer {
+            data32: bus_channels.as_mut_ptr(),
+            data64: std::ptr::null_mut(),
+            channel_count: count as u32,
+            latency: 0,
+            constant_mask: 0,
+        });
+        offset += count;
+    }
+    if offset != channel_ptrs.len() {
+        return Err(napi::Error::from_reason(format!(
+            "bus layout expects {} channels but {} were provided",
+            offset,
+            channel_ptrs.len()
+        )));
+    }
+    Ok(buffers)
+}
+
+/**
+ * Query a plugin's real channel layout through the `clap.audio-ports`
+ * extension. Caches the summed totals in `BusConfig` (read lock-free) and
+ * the per-port channel counts (behind a small dedicated mutex) so `process`
+ * can build one `ClapAudioBuffer` per bus instead of guessing stereo
+ * in/out.
+ */
+#[napi]
+pub fn get_audio_ports(handle: u32, is_input: bool) -> napi::Result<Vec<AudioPortInfo>> {
+    let plugin = {
+        let mut registry = REGISTRY.lock().unwrap();
+        let registry = registry.as_mut().unwrap();
+
+        let plugin_handle = registry
+            .plugins
+            .get(&handle)
+            .ok_or_else(|| napi::Error::from_reason("Invalid plugin handle"))?;
+
+        if plugin_handle.plugin.is_null() {
+            return Err(napi::Error::from_reason("Plugin is not loaded"));
+        }
+
+        plugin_handle.plugin
+    };
+
+    let extension_id = CString::new("clap.audio-ports").unwrap();
+    let ports_ext = unsafe { ((*plugin).get_extension)(plugin, extension_id.as_ptr()) }
+        as *const ClapPluginAudioPorts;
+    if ports_ext.is_null() {
+        return Ok(Vec::new());
+    }
+
+    let count = unsafe { ((*ports_ext).count)(plugin, is_input) };
+    let mut ports = Vec::with_capacity(count as usize);
+    for index in 0..count {
+        let mut raw = ClapAudioPortInfoRaw {
+            id: 0,
+            name: [0; 256],
+            flags: 0,
+            channel_count: 0,
+            port_type: std::ptr::null(),
+            in_place_pair: 0,
+        };
+        let ok =
+            unsafe { ((*ports_ext).get)(plugin, index, is_input, &mut raw as *mut ClapAudioPortInfoRaw) };
+        if !ok {
+            continue;
+        }
+        ports.push(AudioPortInfo {
+            id: raw.id,
+            name: unsafe { fixed_cstr_to_string(&raw.name) },
+            channel_count: raw.channel_count,
+            flags: raw.flags,
+            port_type: unsafe { cstr_to_string(raw.port_type) },
+        });
+    }
+
+    let mut registry = REGISTRY.lock().unwrap();
+    let registry = registry.as_mut().unwrap();
+    if let Some(plugin_handle) = registry.plugins.get_mut(&handle) {
+        let mut bus = plugin_handle.bus_config.load();
+        let total_channels: u32 = ports.iter().map(|p| p.channel_count).sum();
+        let channel_counts: Vec<u32> = ports.iter().map(|p| p.channel_count).collect();
+        if is_input {
+            bus.num_input_channels = total_channels;
+            *plugin_handle.input_port_channels.lock().unwrap() = channel_counts;
+        } else {
+            bus.num_output_channels = total_channels;
+            *plugin_handle.output_port_channels.lock().unwrap() = channel_counts;
+        }
+        plugin_handle.bus_config.store(bus);
+    }
+
+    Ok(ports)
+}
+
+/**
+ * Drain the requests a plugin has made of its `ClapHost` since the last
+ * call: run `on_main_thread()` if `request_callback` fired, and cycle
+ * deactivate/activate if `request_restart` fired. Call this once per JS
+ * tick (e.g. off a timer or animation frame) for every loaded handle.
+ */
+#[napi]
+pub async fn service_host_requests(handle: u32) -> napi::Result<()> {
+    let mut registry = REGISTRY.lock().unwrap();
+    let registry = registry.as_mut().unwrap();
+
+    let plugin_handle = registry
+        .plugins
+        .get_mut(&handle)
+        .ok_or_else(|| napi::Error::from_reason("Invalid plugin handle"))?;
+
+    if plugin_handle.plugin.is_null() {
+        return Ok(());
+    }
+
+    if plugin_handle
+        .host_state
+        .on_main_thread_pending
+        .swap(false, Ordering::SeqCst)
+    {
+        unsafe {
+            ((*plugin_handle.plugin).on_main_thread)(plugin_handle.plugin);
+        }
+    }
+
+    if plugin_handle
+        .host_state
+        .restart_requested
+        .swap(false, Ordering::SeqCst)
+    {
+        if plugin_handle.activated {
+            if plugin_handle.processing {
+                unsafe {
+                    ((*plugin_handle.plugin).stop_processing)(plugin_handle.plugin);
+                }
+                plugin_handle.processing = false;
+            }
+            unsafe {
+                ((*plugin_handle.plugin).deactivate)(plugin_handle.plugin);
+            }
+            plugin_handle.activated = false;
+        }
+        if let Some((sample_rate, min_frames, max_frames)) = plugin_handle.activation_params {
+            let ok = unsafe {
+                ((*plugin_handle.plugin).activate)(
+                    plugin_handle.plugin,
+                    sample_rate,
+                    min_frames,
+                    max_frames,
+                )
+            };
+            plugin_handle.activated = ok;
+        }
+        println!("[ClapBridge] Restarted plugin (id: {}) on host request", handle);
+    }
+
+    Ok(())
+}
+
 /**
  * Deactivate plugin
  */
@@ -350,19 +1357,191 @@ pub async fn stop_processing(handle: u32) -> napi::Result<()> {
     Ok(())
 }
 
+/**
+ * Register a JS callback to receive a plugin's output events (parameter
+ * automation, note-ends, MIDI) collected during `process()`. `process()`
+ * runs on the realtime audio thread, which cannot call into the JS VM
+ * directly, so events are handed off through a napi threadsafe function
+ * invoked in non-blocking mode on the main thread instead.
+ */
+#[napi]
+pub fn on_plugin_events(handle: u32, callback: JsFunction) -> napi::Result<()> {
+    let tsfn: ThreadsafeFunction<Vec<PluginEvent>, ErrorStrategy::Fatal> = callback
+        .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<Vec<PluginEvent>>| {
+            Ok(vec![ctx.value])
+        })?;
+
+    let mut registry = REGISTRY.lock().unwrap();
+    let registry = registry.as_mut().unwrap();
+
+    let plugin_handle = registry
+        .plugins
+        .get_mut(&handle)
+        .ok_or_else(|| napi::Error::from_reason("Invalid plugin handle"))?;
+
+    plugin_handle.events_callback = Some(tsfn);
+
+    Ok(())
+}
+
 /**
  * Process audio
- * NOTE: In real implementation, use SharedArrayBuffer for zero-copy
+ *
+ * `inputs`/`outputs` are one `Float32Array` per channel, backed by a
+ * `SharedArrayBuffer` on the JS side so no copy happens crossing the
+ * boundary; output arrays are written in place. The registry lock is taken
+ * only to read the plugin pointer, the activated frame range and clones of
+ * the handle's shared (`Arc`/threadsafe-function) fields; it is dropped
+ * before any buffer work starts and is not held during the plugin's
+ * `process()` call.
  */
 #[napi]
-pub fn process(handle: u32, /* process_data: ClapProcessData */) -> napi::Result<u32> {
-    // In real implementation:
-    // 1. Get plugin handle
-    // 2. Setup ClapProcess structure with audio buffers
-    // 3. Call plugin->process()
-    // 4. Return status
+pub fn process(
+    handle: u32,
+    inputs: Vec<Float32Array>,
+    mut outputs: Vec<Float32Array>,
+    frames_count: u32,
+) -> napi::Result<u32> {
+    let (
+        plugin,
+        steady_time,
+        min_frames,
+        max_frames,
+        bus_config,
+        input_port_channels,
+        output_port_channels,
+        param_events,
+        events_callback,
+    ) = {
+        let mut registry = REGISTRY.lock().unwrap();
+        let registry = registry.as_mut().unwrap();
+
+        let plugin_handle = registry
+            .plugins
+            .get(&handle)
+            .ok_or_else(|| napi::Error::from_reason("Invalid plugin handle"))?;
+
+        if !plugin_handle.processing || plugin_handle.plugin.is_null() {
+            return Err(napi::Error::from_reason("Plugin is not processing"));
+        }
+
+        let (_, min_frames, max_frames) = plugin_handle
+            .activation_params
+            .ok_or_else(|| napi::Error::from_reason("Plugin has not been activated"))?;
+
+        (
+            plugin_handle.plugin,
+            plugin_handle.steady_time.clone(),
+            min_frames,
+            max_frames,
+            plugin_handle.bus_config.clone(),
+            plugin_handle.input_port_channels.clone(),
+            plugin_handle.output_port_channels.clone(),
+            plugin_handle.param_events.clone(),
+            plugin_handle.events_callback.clone(),
+        )
+    };
+
+    if frames_count < min_frames || frames_count > max_frames {
+        return Err(napi::Error::from_reason(format!(
+            "frames_count {} is outside the activated range [{}, {}]",
+            frames_count, min_frames, max_frames
+        )));
+    }
+
+    // bus_config is read lock-free (AtomicCell, no registry lock held); it's
+    // only used as a fast gate on whether get_audio_ports has recorded a
+    // nonzero channel total for a direction (a zero total, whether never
+    // queried or no ports, falls back to one bus holding every channel JS
+    // passed), so the per-bus channel layout (which needs real heap storage
+    // and so can't live in the AtomicCell) only needs locking when it's
+    // actually present. That per-bus lock is a small, dedicated mutex, never
+    // the big registry one, so a concurrent load/unload still can't stall
+    // the audio thread here.
+    let bus = bus_config.load();
+    let input_layout = if bus.num_input_channels != 0 {
+        input_port_channels.lock().unwrap().clone()
+    } else {
+        vec![inputs.len() as u32]
+    };
+    let output_layout = if bus.num_output_channels != 0 {
+        output_port_channels.lock().unwrap().clone()
+    } else {
+        vec![outputs.len() as u32]
+    };
+
+    validate_bus_layout("input", &input_layout, inputs.len())?;
+    validate_bus_layout("output", &output_layout, outputs.len())?;
+
+    let mut input_channel_ptrs: Vec<*mut f32> =
+        inputs.iter().map(|c| c.as_ptr() as *mut f32).collect();
+    let mut output_channel_ptrs: Vec<*mut f32> =
+        outputs.iter_mut().map(|c| c.as_mut_ptr()).collect();
+
+    // One ClapAudioBuffer per discovered bus, each pointing at that bus's
+    // slice of channels, so a plugin with more than one input/output port
+    // gets a real multi-bus audio_inputs/audio_outputs array instead of
+    // everything being collapsed into a single bus.
+    let mut input_buses = build_bus_buffers(&mut input_channel_ptrs, &input_layout)?;
+    let mut output_buses = build_bus_buffers(&mut output_channel_ptrs, &output_layout)?;
+
+    let current_steady_time = steady_time.load(Ordering::SeqCst);
+
+    let mut pending_params = PendingParamEvents { events: Vec::new() };
+    while let Some(event) = param_events.pop() {
+        pending_params.events.push(event);
+    }
+    let in_events = build_input_events(&pending_params);
+
+    let mut out_events_ctx = OutputEventsCtx {
+        events: Vec::with_capacity(OUT_EVENTS_CAPACITY),
+    };
+    let out_events = build_collecting_output_events(&mut out_events_ctx);
+
+    let clap_process = ClapProcess {
+        steady_time: current_steady_time,
+        frames_count,
+        transport: std::ptr::null(),
+        audio_inputs: input_buses.as_mut_ptr() as *const c_void,
+        audio_outputs: output_buses.as_mut_ptr() as *const c_void,
+        audio_inputs_count: input_buses.len() as u32,
+        audio_outputs_count: output_buses.len() as u32,
+        in_events: &in_events as *const ClapInputEvents as *const c_void,
+        out_events: &out_events as *const ClapOutputEvents as *const c_void,
+    };
+
+    let status = unsafe { ((*plugin).process)(plugin, &clap_process as *const ClapProcess) };
+
+    steady_time.fetch_add(frames_count as i64, Ordering::SeqCst);
+
+    if let Some(tsfn) = events_callback {
+        if !out_events_ctx.events.is_empty() {
+            let events: Vec<PluginEvent> = out_events_ctx
+                .events
+                .into_iter()
+                .map(PluginEvent::from)
+                .collect();
+            tsfn.call(events, ThreadsafeFunctionCallMode::NonBlocking);
+        }
+    }
+
+    Ok(status)
+}
+
+fn lookup_plugin(handle: u32) -> napi::Result<*const ClapPlugin> {
+    let mut registry = REGISTRY.lock().unwrap();
+    let registry = registry.as_mut().unwrap();
+
+    let plugin_handle = registry
+        .plugins
+        .get(&handle)
+        .ok_or_else(|| napi::Error::from_reason("Invalid plugin handle"))?;
+
+    if plugin_handle.plugin.is_null() {
+        return Err(napi::Error::from_reason("Plugin is not loaded"));
+    }
 
-    Ok(1) // CLAP_PROCESS_CONTINUE
+    Ok(plugin_handle.plugin)
 }
 
 /**
@@ -370,23 +1549,125 @@ pub fn process(handle: u32, /* process_data: ClapProcessData */) -> napi::Result
  */
 #[napi]
 pub fn get_parameter_count(handle: u32) -> napi::Result<u32> {
-    // In real implementation:
-    // 1. Get plugin handle
-    // 2. Get params extension
-    // 3. Call params->count()
+    let plugin = lookup_plugin(handle)?;
+    let params_ext = unsafe { params_extension_of(plugin) };
+    if params_ext.is_null() {
+        return Ok(0);
+    }
+    Ok(unsafe { ((*params_ext).count)(plugin) })
+}
+
+/**
+ * Get parameter info (id, name, min/max/default, flags) by index.
+ */
+#[napi]
+pub fn get_parameter_info(handle: u32, index: u32) -> napi::Result<ParamInfo> {
+    let plugin = lookup_plugin(handle)?;
+    let params_ext = unsafe { params_extension_of(plugin) };
+    if params_ext.is_null() {
+        return Err(napi::Error::from_reason("Plugin does not implement clap.params"));
+    }
 
-    Ok(0)
+    let mut raw = ClapParamInfoRaw {
+        id: 0,
+        flags: 0,
+        cookie: std::ptr::null_mut(),
+        name: [0; 256],
+        module: [0; 1024],
+        min_value: 0.0,
+        max_value: 0.0,
+        default_value: 0.0,
+    };
+    let ok = unsafe { ((*params_ext).get_info)(plugin, index, &mut raw as *mut ClapParamInfoRaw) };
+    if !ok {
+        return Err(napi::Error::from_reason(format!(
+            "params->get_info() failed for index {}",
+            index
+        )));
+    }
+
+    Ok(ParamInfo {
+        id: raw.id,
+        name: unsafe { fixed_cstr_to_string(&raw.name) },
+        min_value: raw.min_value,
+        max_value: raw.max_value,
+        default_value: raw.default_value,
+        flags: raw.flags,
+    })
 }
 
 /**
  * Set parameter value
+ *
+ * In CLAP, parameter changes aren't set directly; they're delivered as a
+ * `CLAP_EVENT_PARAM_VALUE` event. This enqueues one onto the handle's
+ * lock-free ring buffer. If the plugin is currently processing, the next
+ * `process()` call drains the buffer into `in_events`. Otherwise, if it's
+ * at least activated, flush the buffer immediately through
+ * `params->flush()` so the change isn't delayed until playback resumes.
  */
 #[napi]
 pub fn set_parameter_value(handle: u32, param_id: u32, value: f64) -> napi::Result<()> {
-    // In real implementation:
-    // 1. Get plugin handle
-    // 2. Get params extension
-    // 3. Call params->set_value()
+    let (plugin, processing, activated, param_events) = {
+        let mut registry = REGISTRY.lock().unwrap();
+        let registry = registry.as_mut().unwrap();
+
+        let plugin_handle = registry
+            .plugins
+            .get(&handle)
+            .ok_or_else(|| napi::Error::from_reason("Invalid plugin handle"))?;
+
+        if plugin_handle.plugin.is_null() {
+            return Err(napi::Error::from_reason("Plugin is not loaded"));
+        }
+
+        (
+            plugin_handle.plugin,
+            plugin_handle.processing,
+            plugin_handle.activated,
+            plugin_handle.param_events.clone(),
+        )
+    };
+
+    let event = ClapEventParamValue {
+        header: ClapEventHeader {
+            size: std::mem::size_of::<ClapEventParamValue>() as u32,
+            time: 0,
+            space_id: 0,
+            event_type: CLAP_EVENT_PARAM_VALUE,
+            flags: 0,
+        },
+        param_id,
+        cookie: std::ptr::null_mut(),
+        note_id: -1,
+        port_index: -1,
+        channel: -1,
+        key: -1,
+        value,
+    };
+
+    param_events
+        .push(event)
+        .map_err(|_| napi::Error::from_reason("Parameter event queue is full"))?;
+
+    if !processing && activated {
+        let params_ext = unsafe { params_extension_of(plugin) };
+        if !params_ext.is_null() {
+            let mut pending = PendingParamEvents { events: Vec::new() };
+            while let Some(event) = param_events.pop() {
+                pending.events.push(event);
+            }
+            let in_events = build_input_events(&pending);
+            let out_events = build_empty_output_events();
+            unsafe {
+                ((*params_ext).flush)(
+                    plugin,
+                    &in_events as *const ClapInputEvents,
+                    &out_events as *const ClapOutputEvents,
+                );
+            }
+        }
+    }
 
     Ok(())
 }
@@ -396,12 +1677,288 @@ pub fn set_parameter_value(handle: u32, param_id: u32, value: f64) -> napi::Resu
  */
 #[napi]
 pub fn get_parameter_value(handle: u32, param_id: u32) -> napi::Result<f64> {
-    // In real implementation:
-    // 1. Get plugin handle
-    // 2. Get params extension
-    // 3. Call params->get_value()
+    let plugin = lookup_plugin(handle)?;
+    let params_ext = unsafe { params_extension_of(plugin) };
+    if params_ext.is_null() {
+        return Err(napi::Error::from_reason("Plugin does not implement clap.params"));
+    }
+
+    let mut value = 0.0f64;
+    let ok = unsafe { ((*params_ext).get_value)(plugin, param_id, &mut value as *mut f64) };
+    if !ok {
+        return Err(napi::Error::from_reason(format!(
+            "params->get_value() failed for param {}",
+            param_id
+        )));
+    }
+
+    Ok(value)
+}
+
+/**
+ * Save a plugin's full preset state through the `clap.state` extension.
+ * `state->save()` streams bytes out via `write()` callbacks, which we
+ * collect into a growing `Vec<u8>` and hand back as a `Buffer`.
+ */
+#[napi]
+pub fn save_state(handle: u32) -> napi::Result<Buffer> {
+    let plugin = lookup_plugin(handle)?;
+    let state_ext = unsafe { state_extension_of(plugin) };
+    if state_ext.is_null() {
+        return Err(napi::Error::from_reason("Plugin does not implement clap.state"));
+    }
+
+    let mut ctx = OStreamCtx { buffer: Vec::new() };
+    let ostream = ClapOStream {
+        ctx: &mut ctx as *mut OStreamCtx as *mut c_void,
+        write: ostream_write,
+    };
+
+    let ok = unsafe { ((*state_ext).save)(plugin, &ostream as *const ClapOStream) };
+    if !ok {
+        return Err(napi::Error::from_reason("state->save() failed"));
+    }
+
+    Ok(ctx.buffer.into())
+}
+
+/**
+ * Restore a plugin's full preset state through the `clap.state` extension.
+ * Wraps `data` in a `clap_istream` whose `read()` callback serves bytes
+ * from the buffer, tracking an offset across however many reads
+ * `state->load()` chooses to issue.
+ */
+#[napi]
+pub fn load_state(handle: u32, data: Buffer) -> napi::Result<()> {
+    let plugin = lookup_plugin(handle)?;
+    let state_ext = unsafe { state_extension_of(plugin) };
+    if state_ext.is_null() {
+        return Err(napi::Error::from_reason("Plugin does not implement clap.state"));
+    }
+
+    let mut ctx = IStreamCtx {
+        data: data.to_vec(),
+        offset: 0,
+    };
+    let istream = ClapIStream {
+        ctx: &mut ctx as *mut IStreamCtx as *mut c_void,
+        read: istream_read,
+    };
+
+    let ok = unsafe { ((*state_ext).load)(plugin, &istream as *const ClapIStream) };
+    if !ok {
+        return Err(napi::Error::from_reason("state->load() failed"));
+    }
+
+    Ok(())
+}
+
+/**
+ * A plugin discovered by scanning the CLAP search paths, cached so
+ * `load_plugin_by_id` can resolve an id to the bundle it came from without
+ * rescanning the filesystem.
+ */
+#[derive(Clone)]
+struct ScannedPlugin {
+    bundle_path: String,
+    info: PluginInfo,
+}
+
+/**
+ * Separates bundle *discovery* (enumerate descriptors by opening each
+ * bundle just long enough to read them) from *instantiation* (actually
+ * create a plugin, which goes through `load_plugin`/`load_plugin_by_id` and
+ * keeps its own `Library` handle alive for as long as the instance lives).
+ */
+struct PluginManager {
+    plugins: HashMap<String, ScannedPlugin>,
+}
+
+static MANAGER: Mutex<Option<PluginManager>> = Mutex::new(None);
+
+fn init_manager() {
+    let mut manager = MANAGER.lock().unwrap();
+    if manager.is_none() {
+        *manager = Some(PluginManager {
+            plugins: HashMap::new(),
+        });
+    }
+}
+
+/**
+ * The platform CLAP bundle search paths, in the order a host should prefer
+ * them, plus whatever extra directories `$CLAP_PATH` adds.
+ */
+fn clap_search_paths() -> Vec<std::path::PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(home) = std::env::var_os("HOME") {
+        paths.push(Path::new(&home).join(".clap"));
+        #[cfg(target_os = "macos")]
+        paths.push(Path::new(&home).join("Library/Audio/Plug-Ins/CLAP"));
+    }
+
+    #[cfg(target_os = "linux")]
+    paths.push(std::path::PathBuf::from("/usr/lib/clap"));
+
+    #[cfg(target_os = "windows")]
+    if let Some(common) = std::env::var_os("COMMONPROGRAMFILES") {
+        paths.push(Path::new(&common).join("CLAP"));
+    }
+
+    if let Some(extra) = std::env::var_os("CLAP_PATH") {
+        paths.extend(std::env::split_paths(&extra));
+    }
+
+    paths
+}
+
+/**
+ * List every `.clap` bundle found directly under the search paths. Bundles
+ * are single files (or, on macOS, bundle directories) with a `.clap`
+ * extension; we don't recurse beneath that.
+ */
+fn discover_bundles() -> Vec<std::path::PathBuf> {
+    let mut bundles = Vec::new();
+    for dir in clap_search_paths() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().map(|ext| ext == "clap").unwrap_or(false) {
+                bundles.push(path);
+            }
+        }
+    }
+    bundles
+}
+
+/**
+ * Open a bundle just long enough to read its factory descriptors. Errors
+ * (missing symbol, failed init, ...) are swallowed and the bundle is
+ * skipped, since a single malformed `.clap` shouldn't fail the whole scan.
+ */
+fn scan_bundle(path: &std::path::Path) -> Vec<PluginInfo> {
+    let path_str = path.to_string_lossy().to_string();
+
+    let library = match unsafe { Library::new(&path_str) } {
+        Ok(library) => library,
+        Err(_) => return Vec::new(),
+    };
+
+    let (entry, factory) = match unsafe { open_bundle(&library, &path_str) } {
+        Ok(pair) => pair,
+        Err(_) => return Vec::new(),
+    };
+
+    let count = unsafe { ((*factory).get_plugin_count)(factory) };
+    let mut infos = Vec::with_capacity(count as usize);
+    for index in 0..count {
+        let desc = unsafe { ((*factory).get_plugin_descriptor)(factory, index) };
+        if desc.is_null() {
+            continue;
+        }
+        infos.push(unsafe { descriptor_to_info(desc) });
+    }
+
+    unsafe { ((*entry).deinit)() };
+
+    infos
+}
+
+/**
+ * Scan the standard CLAP search paths and rebuild the in-memory plugin
+ * index from scratch.
+ */
+#[napi]
+pub fn scan_plugins() -> napi::Result<Vec<PluginInfo>> {
+    init_manager();
+    let mut manager = MANAGER.lock().unwrap();
+    let manager = manager.as_mut().unwrap();
+
+    manager.plugins.clear();
+    let mut all = Vec::new();
+    for bundle_path in discover_bundles() {
+        let bundle_path_str = bundle_path.to_string_lossy().to_string();
+        for info in scan_bundle(&bundle_path) {
+            manager.plugins.insert(
+                info.id.clone(),
+                ScannedPlugin {
+                    bundle_path: bundle_path_str.clone(),
+                    info: info.clone(),
+                },
+            );
+            all.push(info);
+        }
+    }
+
+    println!(
+        "[ClapBridge] Scanned {} plugin(s) across the CLAP search paths",
+        all.len()
+    );
+
+    Ok(all)
+}
+
+/**
+ * The plugins added and removed since the previous `scan_plugins()`/
+ * `rescan()` call, so a UI can update its plugin list incrementally.
+ */
+#[napi(object)]
+pub struct RescanDiff {
+    pub added: Vec<PluginInfo>,
+    pub removed: Vec<String>,
+}
+
+/**
+ * Rescan the CLAP search paths and diff the result against the previous
+ * scan.
+ */
+#[napi]
+pub fn rescan() -> napi::Result<RescanDiff> {
+    init_manager();
+
+    let previous_ids: HashSet<String> = {
+        let manager = MANAGER.lock().unwrap();
+        manager.as_ref().unwrap().plugins.keys().cloned().collect()
+    };
+
+    let current = scan_plugins()?;
+    let current_ids: HashSet<String> = current.iter().map(|info| info.id.clone()).collect();
+
+    let added = current
+        .into_iter()
+        .filter(|info| !previous_ids.contains(&info.id))
+        .collect();
+    let removed = previous_ids.difference(&current_ids).cloned().collect();
+
+    Ok(RescanDiff { added, removed })
+}
+
+/**
+ * Load a plugin previously discovered by `scan_plugins`/`rescan`, resolving
+ * its id to the bundle it came from.
+ */
+#[napi]
+pub async fn load_plugin_by_id(id: String) -> napi::Result<u32> {
+    let bundle_path = {
+        init_manager();
+        let manager = MANAGER.lock().unwrap();
+        let manager = manager.as_ref().unwrap();
+        manager
+            .plugins
+            .get(&id)
+            .map(|scanned| scanned.bundle_path.clone())
+            .ok_or_else(|| {
+                napi::Error::from_reason(format!(
+                    "No scanned plugin with id {}; call scan_plugins() first",
+                    id
+                ))
+            })?
+    };
 
-    Ok(0.0)
+    load_plugin(bundle_path, Some(id)).await
 }
 
 /**
@@ -420,6 +1977,7 @@ pub fn get_parameter_value(handle: u32, param_id: u32) -> napi::Result<f64> {
  *    napi = "2"
  *    napi-derive = "2"
  *    libloading = "0.8"
+ *    crossbeam = "0.8"
  *    clap-sys = "0.3"  # For production
  *
  *    [build-dependencies]